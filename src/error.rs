@@ -0,0 +1,18 @@
+//! Typed errors for `PuzzleSolution::get_solution_commitment`, `PuzzleSolution::get_solution_proof`
+//! and `PuzzleSolutionProof`. The adaptor-signature functions (`get_solution_adaptor_proof` and the
+//! `PuzzleSolutionAdaptorProof` methods) do not use this type; they return `anyhow` errors.
+
+use thiserror::Error;
+
+/// PuzzleError collects the failure modes of deriving a commitment or producing/verifying a proof.
+#[derive(Debug, Error)]
+pub enum PuzzleError {
+    #[error("invalid solution")]
+    InvalidSolution,
+    #[error("invalid proof")]
+    InvalidProof,
+    #[error("wallet error: {0}")]
+    Wallet(#[from] ethers_signers::WalletError),
+    #[error("signature error: {0}")]
+    Signature(#[from] ethers_core::types::SignatureError),
+}
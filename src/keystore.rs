@@ -0,0 +1,98 @@
+//! Encrypted on-disk persistence for the signing key derived by `get_solution_commitment`.
+
+use std::path::Path;
+
+use ethers_core::types::Address;
+use ethers_signers::Wallet;
+use rand::rngs::OsRng;
+use thiserror::Error;
+
+use crate::PuzzleSolution;
+
+/// KeystoreError collects the failure modes of saving or loading a commitment keystore.
+#[derive(Debug, Error)]
+pub enum KeystoreError {
+    #[error("keystore error: {0}")]
+    Keystore(#[from] eth_keystore::KeystoreError),
+    #[error("wallet error: {0}")]
+    Wallet(#[from] ethers_signers::WalletError),
+    #[error("invalid solution commitment: {0}")]
+    Commitment(#[from] crate::PuzzleError),
+}
+
+impl PuzzleSolution {
+    /// save_commitment_keystore derives the solution commitment and persists its signing key to
+    /// `path` as a password-encrypted Web3 keystore, returning the committed address.
+    pub fn save_commitment_keystore<H: digest::Digest, P: AsRef<Path>>(
+        &self,
+        path: P,
+        password: &str,
+    ) -> Result<Address, KeystoreError> {
+        let (w, address) = self.get_solution_commitment::<H>()?;
+
+        save_wallet_keystore(&w, path, password)?;
+        Ok(address)
+    }
+
+    /// from_keystore reloads a signing key saved with `save_commitment_keystore`, returning the
+    /// `Wallet` and its address.
+    pub fn from_keystore<P: AsRef<Path>>(
+        path: P,
+        password: &str,
+    ) -> Result<(Wallet<ecdsa::SigningKey<k256::Secp256k1>>, Address), KeystoreError> {
+        let w = load_wallet_keystore(path, password)?;
+        let address = w.address();
+        Ok((w, address))
+    }
+}
+
+/// save_wallet_keystore persists `w`'s signing key to `path` as an encrypted Web3 keystore.
+pub fn save_wallet_keystore<P: AsRef<Path>>(
+    w: &Wallet<ecdsa::SigningKey<k256::Secp256k1>>,
+    path: P,
+    password: &str,
+) -> Result<(), KeystoreError> {
+    let dir = path.as_ref().parent().unwrap_or_else(|| Path::new("."));
+    let name = path.as_ref().file_name().and_then(|n| n.to_str());
+
+    eth_keystore::encrypt_key(dir, &mut OsRng, w.signer().to_bytes(), password, name)?;
+    Ok(())
+}
+
+/// load_wallet_keystore reloads a signing key previously saved with [`save_wallet_keystore`].
+pub fn load_wallet_keystore<P: AsRef<Path>>(
+    path: P,
+    password: &str,
+) -> Result<Wallet<ecdsa::SigningKey<k256::Secp256k1>>, KeystoreError> {
+    let secret = eth_keystore::decrypt_key(path, password)?;
+    Ok(Wallet::from_bytes(&secret)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_and_load_commitment_keystore() {
+        let solutions = vec![
+            "solution1".to_string(),
+            "solution2".to_string(),
+            "solution3".to_string(),
+        ];
+        let puzzle_solution = PuzzleSolution::new(solutions);
+
+        let mut path = std::env::temp_dir();
+        path.push(format!("puzzle-solution-keystore-{}.json", std::process::id()));
+
+        let address = puzzle_solution
+            .save_commitment_keystore::<sha2::Sha256, _>(&path, "correct horse battery staple")
+            .unwrap();
+
+        let (_, loaded_address) =
+            PuzzleSolution::from_keystore(&path, "correct horse battery staple").unwrap();
+
+        assert!(loaded_address == address);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -6,6 +6,22 @@ use ethers_core::utils::keccak256;
 use ethers_signers::{Signer, Wallet};
 use digest::Digest;
 use anyhow::Result;
+use ecdsa_fun::adaptor::{Adaptor, EncryptedSignature};
+use ecdsa_fun::fun::{g, marker::*, Point, Scalar, G};
+use ecdsa_fun::nonce::Deterministic;
+use ecdsa_fun::HashTranscript;
+
+/// Deterministic ECDSA adaptor-signature scheme used for the swap-settlement proofs below.
+type PuzzleAdaptor = Adaptor<HashTranscript<sha2::Sha256>, Deterministic<sha2::Sha256>>;
+
+mod error;
+pub mod keystore;
+pub mod onchain;
+pub mod pedersen;
+
+pub use error::PuzzleError;
+pub use keystore::KeystoreError;
+pub use pedersen::{PedersenCommitment, PedersenOpening, PedersenProof, PedersenPuzzleSolution};
 
 /// PuzzleSolution is a struct that contains a set of `solutions` to a puzzle and the key `g` used to commit to them.
 /// The solutions are expected as strings.
@@ -27,7 +43,7 @@ impl PuzzleSolution{
     }
 
     /// get_solution_commitment returns the commitment to the solution set.
-    pub fn get_solution_commitment<H: Digest>(&self) -> Result<( Wallet<ecdsa::SigningKey<k256::Secp256k1>>, Address)>  {
+    pub fn get_solution_commitment<H: Digest>(&self) -> Result<(Wallet<ecdsa::SigningKey<k256::Secp256k1>>, Address), PuzzleError>  {
         // Compute chain of hashes of each solution in the solution set
         // and commit to the last hash in the chain
         let mut hasher = H::new();
@@ -50,13 +66,12 @@ impl PuzzleSolution{
         w: Wallet<ecdsa::SigningKey<k256::Secp256k1>>,
         solution_commitment: Address,
         m_s: Address,
-    ) -> Result<PuzzleSolutionProof> {
+    ) -> Result<PuzzleSolutionProof, PuzzleError> {
         // Check that w is correct solution
-        //TODO: Better error handling
         let h = w.address();
         match h == solution_commitment {
             true => (),
-            false => return Err(anyhow::Error::msg("Invalid solution")),
+            false => return Err(PuzzleError::InvalidSolution),
         }
 
         // Compute hash of m_s
@@ -74,7 +89,7 @@ impl PuzzleSolutionProof {
     pub fn verify(
         &self,
         solution_commitment: Address,
-    ) -> Result<bool> {
+    ) -> Result<bool, PuzzleError> {
 
         // Verify proof
         let m_s_hash = H256::from(keccak256(self.m_s.encode()));
@@ -86,7 +101,7 @@ impl PuzzleSolutionProof {
     pub fn verify_and_export(
         &self,
         solution_commitment: Address,
-    ) -> Result<(Vec<u8>, Vec<u8>, Vec<u8>, u8, Vec<u8> )> {
+    ) -> Result<(Vec<u8>, Vec<u8>, Vec<u8>, u8, Vec<u8>), PuzzleError> {
   
         // Verify proof
         let m_s_bytes = self.m_s.clone();
@@ -107,9 +122,144 @@ impl PuzzleSolutionProof {
                     m_s_bytes.as_bytes().to_vec(),
                 ))
             }
-            false => Err(anyhow::Error::msg("Invalid proof")),
+            false => Err(PuzzleError::InvalidProof),
+        }
+    }
+}
+
+/// PuzzleSolutionAdaptorProof is a pre-signature over `m_s`, encrypted under a counterparty's
+/// `encryption_point`. Decrypting it with that point's discrete log yields a normal `Signature`.
+pub struct PuzzleSolutionAdaptorProof {
+    pub pre_sig: EncryptedSignature,
+    pub verification_key: Point,
+    pub m_s: Address,
+}
+
+impl PuzzleSolution {
+    /// get_solution_adaptor_proof returns an adaptor pre-signature proof of the solution,
+    /// encrypted under the counterparty's `encryption_point`.
+    pub fn get_solution_adaptor_proof(
+        &self,
+        w: Wallet<ecdsa::SigningKey<k256::Secp256k1>>,
+        solution_commitment: Address,
+        m_s: Address,
+        encryption_point: Point,
+    ) -> Result<PuzzleSolutionAdaptorProof> {
+        // Check that w is correct solution
+        let h = w.address();
+        match h == solution_commitment {
+            true => (),
+            false => return Err(anyhow::Error::msg("Invalid solution")),
+        }
+
+        let signing_key = signing_key_to_scalar(&w)?;
+        let verification_key = g!(signing_key * G).normalize();
+
+        let m_s_hash = keccak256(m_s.encode());
+        let adaptor = PuzzleAdaptor::default();
+        let pre_sig = adaptor.encrypted_sign(&signing_key, &encryption_point, &m_s_hash);
+
+        Ok(PuzzleSolutionAdaptorProof { pre_sig, verification_key, m_s })
+    }
+}
+
+impl PuzzleSolutionAdaptorProof {
+    /// verify_adaptor returns true if `self.pre_sig` is a valid encryption, under `encryption_point`,
+    /// of a signature over `self.m_s` by `solution_commitment`.
+    pub fn verify_adaptor(
+        &self,
+        solution_commitment: Address,
+        encryption_point: Point,
+    ) -> Result<bool> {
+        if point_to_address(&self.verification_key) != solution_commitment {
+            return Ok(false);
+        }
+
+        let m_s_hash = keccak256(self.m_s.encode());
+        let adaptor = PuzzleAdaptor::default();
+        Ok(adaptor.verify_encrypted_signature(
+            &self.verification_key,
+            &encryption_point,
+            &m_s_hash,
+            &self.pre_sig,
+        ))
+    }
+
+    /// decrypt completes `self.pre_sig` into a normal recoverable `Signature` given `y`.
+    pub fn decrypt(&self, y: Scalar) -> Result<Signature> {
+        let adaptor = PuzzleAdaptor::default();
+        let sig = adaptor.decrypt_signature(&y, self.pre_sig.clone());
+        let m_s_hash = H256::from(keccak256(self.m_s.encode()));
+        recoverable_signature(sig, &self.verification_key, m_s_hash)
+    }
+
+    /// recover_decryption_key extracts `y` from a published `full_sig` and the pre-signature.
+    pub fn recover_decryption_key(
+        &self,
+        encryption_point: Point,
+        full_sig: &Signature,
+    ) -> Result<Scalar> {
+        let sig = ethers_signature_to_ecdsa_fun(full_sig)?;
+        let adaptor = PuzzleAdaptor::default();
+        adaptor
+            .recover_decryption_key(&encryption_point, &sig, &self.pre_sig)
+            .ok_or_else(|| anyhow::Error::msg("Full signature does not match pre-signature"))
+    }
+}
+
+/// signing_key_to_scalar converts `w`'s `k256` signing key into a `secp256kfun` scalar.
+fn signing_key_to_scalar(w: &Wallet<ecdsa::SigningKey<k256::Secp256k1>>) -> Result<Scalar> {
+    let bytes = w.signer().to_bytes();
+    Scalar::from_bytes(bytes.into())
+        .and_then(|s| s.non_zero())
+        .ok_or_else(|| anyhow::Error::msg("Invalid signing key"))
+}
+
+/// point_to_address derives the Ethereum address corresponding to a `secp256kfun` public key.
+fn point_to_address(point: &Point) -> Address {
+    let uncompressed = point.to_bytes_uncompressed();
+    let hash = keccak256(&uncompressed[1..]);
+    Address::from_slice(&hash[12..])
+}
+
+/// ethers_signature_to_ecdsa_fun converts a recoverable `Signature` into an `ecdsa_fun::Signature`.
+fn ethers_signature_to_ecdsa_fun(sig: &Signature) -> Result<ecdsa_fun::Signature> {
+    let mut r_bytes = [0u8; 32];
+    sig.r.to_big_endian(&mut r_bytes);
+    let mut s_bytes = [0u8; 32];
+    sig.s.to_big_endian(&mut s_bytes);
+
+    let r = Scalar::from_bytes(r_bytes)
+        .and_then(|s| s.non_zero())
+        .ok_or_else(|| anyhow::Error::msg("Invalid signature r"))?;
+    let s = Scalar::from_bytes(s_bytes)
+        .and_then(|s| s.non_zero())
+        .ok_or_else(|| anyhow::Error::msg("Invalid signature s"))?;
+
+    Ok(ecdsa_fun::Signature { R_x: r, s })
+}
+
+/// recoverable_signature re-attaches the recovery id `ecdsa_fun` drops, trying both candidates
+/// against `verification_key`.
+fn recoverable_signature(
+    sig: ecdsa_fun::Signature,
+    verification_key: &Point,
+    m_s_hash: H256,
+) -> Result<Signature> {
+    let expected = point_to_address(verification_key);
+    let r = H256::from_slice(&sig.R_x.to_bytes());
+    let s = H256::from_slice(&sig.s.to_bytes());
+
+    for v in 0u64..=1 {
+        let candidate = Signature { r: r.into(), s: s.into(), v };
+        if let Ok(recovered) = candidate.recover(m_s_hash) {
+            if recovered == expected {
+                return Ok(candidate);
+            }
         }
     }
+
+    Err(anyhow::Error::msg("Unable to determine recovery id for decrypted signature"))
 }
 
 // Tests
@@ -216,4 +366,38 @@ mod tests {
         assert!(v_abi == 0 || v_abi == 1 );
         assert!(m_s_bytes.len() == 20);
     }
+
+    // Test for get_solution_adaptor_proof, verify_adaptor, decrypt, and recover_decryption_key
+    #[tokio::test]
+    async fn test_adaptor_signature_round_trip() {
+        let solutions = vec![
+            "solution1".to_string(),
+            "solution2".to_string(),
+            "solution3".to_string(),
+        ];
+        let puzzle_solution = PuzzleSolution::new(solutions);
+        let (w, puzzle_commitment) = puzzle_solution.get_solution_commitment::<sha2::Sha256>().unwrap();
+        let m_s = LocalWallet::new(&mut rand::thread_rng()).address();
+
+        let y = Scalar::random(&mut rand::thread_rng());
+        let encryption_point = g!(y * G).normalize();
+
+        let adaptor_proof = puzzle_solution
+            .get_solution_adaptor_proof(w, puzzle_commitment, m_s, encryption_point)
+            .unwrap();
+
+        assert!(
+            adaptor_proof
+                .verify_adaptor(puzzle_commitment, encryption_point)
+                .unwrap()
+        );
+
+        let full_sig = adaptor_proof.decrypt(y).unwrap();
+        assert!(full_sig.recover(H256::from(keccak256(m_s.encode()))).unwrap() == puzzle_commitment);
+
+        let recovered_y = adaptor_proof
+            .recover_decryption_key(encryption_point, &full_sig)
+            .unwrap();
+        assert!(recovered_y == y);
+    }
 }
@@ -0,0 +1,68 @@
+// Hand-written typed wrapper around `contracts/PuzzleVerifier.abi.json`, in the shape
+// `ethers_contract::Abigen::generate()` would produce. Edit directly; nothing regenerates this.
+#![allow(clippy::all)]
+
+use std::sync::Arc;
+use ethers_contract::builders::ContractCall;
+use ethers_contract::ContractInstance;
+use ethers_core::abi::Abi;
+use ethers_core::types::Address;
+use ethers_providers::Middleware;
+use once_cell::sync::Lazy;
+
+pub static PUZZLEVERIFIER_ABI: Lazy<Abi> = Lazy::new(|| {
+    serde_json::from_str(include_str!("../../contracts/PuzzleVerifier.abi.json"))
+        .expect("invalid PuzzleVerifier ABI")
+});
+
+/// Typed binding for the `PuzzleVerifier` contract, written by hand against its ABI.
+#[derive(Clone)]
+pub struct PuzzleVerifier<M>(ContractInstance<Arc<M>, M>);
+
+impl<M: Middleware> std::ops::Deref for PuzzleVerifier<M> {
+    type Target = ContractInstance<Arc<M>, M>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<M: Middleware> From<ContractInstance<Arc<M>, M>> for PuzzleVerifier<M> {
+    fn from(contract: ContractInstance<Arc<M>, M>) -> Self {
+        Self(contract)
+    }
+}
+
+impl<M: Middleware> PuzzleVerifier<M> {
+    /// Creates a new binding for an already-deployed `PuzzleVerifier` at `address`.
+    pub fn new<T: Into<Address>>(address: T, client: Arc<M>) -> Self {
+        ethers_contract::Contract::new(address.into(), PUZZLEVERIFIER_ABI.clone(), client).into()
+    }
+
+    /// `submitProof(address,address,bytes32,bytes32,uint8)`.
+    pub fn submit_proof(
+        &self,
+        solution_commitment: Address,
+        m_s: Address,
+        r: [u8; 32],
+        s: [u8; 32],
+        v: u8,
+    ) -> ContractCall<M, ()> {
+        self.0
+            .method_hash([0xb3, 0x6a, 0x15, 0x6c], (solution_commitment, m_s, r, s, v))
+            .expect("method not found (this should never happen)")
+    }
+
+    /// `isSolved(address)`.
+    pub fn is_solved(&self, solution_commitment: Address) -> ContractCall<M, bool> {
+        self.0
+            .method_hash([0x54, 0xa8, 0x74, 0xb4], solution_commitment)
+            .expect("method not found (this should never happen)")
+    }
+
+    /// `solved(address)`.
+    pub fn solved(&self, solution_commitment: Address) -> ContractCall<M, bool> {
+        self.0
+            .method_hash([0x5e, 0x36, 0xbd, 0xc6], solution_commitment)
+            .expect("method not found (this should never happen)")
+    }
+}
@@ -0,0 +1,62 @@
+//! On-chain submission of `PuzzleSolutionProof`s against a deployed `PuzzleVerifier` contract.
+
+mod bindings;
+pub use bindings::PuzzleVerifier;
+
+use std::sync::Arc;
+use ethers_core::types::{Address, TransactionReceipt};
+use ethers_providers::Middleware;
+use anyhow::Result;
+
+/// PuzzleVerifierClient binds the typed `PuzzleVerifier` wrapper to a specific deployment and client.
+pub struct PuzzleVerifierClient<M> {
+    contract: PuzzleVerifier<M>,
+}
+
+impl<M: Middleware + 'static> PuzzleVerifierClient<M> {
+    /// Binds to a `PuzzleVerifier` already deployed at `contract_addr`.
+    pub fn new(provider: Arc<M>, contract_addr: Address) -> Self {
+        Self { contract: PuzzleVerifier::new(contract_addr, provider) }
+    }
+}
+
+/// submit_proof broadcasts a `submitProof` transaction for the `(r, s, v, m_s)` tuple produced by
+/// `PuzzleSolutionProof::verify_and_export`.
+pub async fn submit_proof<M: Middleware + 'static>(
+    provider: Arc<M>,
+    contract_addr: Address,
+    proof: (Vec<u8>, Vec<u8>, u8, Vec<u8>),
+    solution_commitment: Address,
+) -> Result<TransactionReceipt> {
+    let (r_abi, s_abi, v_abi, m_s_bytes) = proof;
+
+    let client = PuzzleVerifierClient::new(provider, contract_addr);
+
+    let r: [u8; 32] = r_abi
+        .as_slice()
+        .try_into()
+        .map_err(|_| anyhow::Error::msg("r must be 32 bytes"))?;
+    let s: [u8; 32] = s_abi
+        .as_slice()
+        .try_into()
+        .map_err(|_| anyhow::Error::msg("s must be 32 bytes"))?;
+    let m_s_array: [u8; 20] = m_s_bytes
+        .as_slice()
+        .try_into()
+        .map_err(|_| anyhow::Error::msg("m_s must be 20 bytes"))?;
+    let m_s = Address::from(m_s_array);
+
+    // `v_abi` is the recovery id (0 or 1); `ecrecover` only accepts 27/28.
+    let v = v_abi + 27;
+
+    let call = client
+        .contract
+        .submit_proof(solution_commitment, m_s, r, s, v);
+
+    let pending_tx = call.send().await?;
+    let receipt = pending_tx
+        .await?
+        .ok_or_else(|| anyhow::Error::msg("submitProof transaction dropped from the mempool"))?;
+
+    Ok(receipt)
+}
@@ -0,0 +1,207 @@
+//! An alternative, hiding commitment scheme for puzzle solutions: a Pedersen commitment
+//! `C = g^s . h^r` with a Fiat-Shamir Sigma-protocol proof of knowledge bound to a solver address.
+
+use digest::Digest;
+use ethers_core::abi::AbiEncode;
+use ethers_core::types::Address;
+use ethers_core::utils::keccak256;
+use k256::elliptic_curve::ops::Reduce;
+use k256::elliptic_curve::{Field, PrimeField};
+use k256::{ProjectivePoint, Scalar, U256};
+use rand::rngs::OsRng;
+use anyhow::Result;
+
+/// PedersenPuzzleSolution is a set of `solutions` to a puzzle, committed to with a hiding
+/// Pedersen commitment rather than a bare hash.
+pub struct PedersenPuzzleSolution {
+    solutions: Vec<String>,
+}
+
+/// PedersenOpening is the secret opening (`s`, `r`) of a `PedersenCommitment`.
+pub struct PedersenOpening {
+    s: Scalar,
+    r: Scalar,
+}
+
+/// PedersenCommitment is the public commitment `C = g^s . h^r`.
+#[derive(Clone, Copy)]
+pub struct PedersenCommitment {
+    pub c: ProjectivePoint,
+}
+
+/// PedersenProof is a Fiat-Shamir proof of knowledge of a `PedersenCommitment`'s opening, bound
+/// to a solver address `m_s`.
+pub struct PedersenProof {
+    pub t: ProjectivePoint,
+    pub z1: Scalar,
+    pub z2: Scalar,
+}
+
+impl PedersenPuzzleSolution {
+    pub fn new(solutions: Vec<String>) -> Self {
+        Self { solutions }
+    }
+
+    /// commit returns the opening of, and the public commitment to, the solution set.
+    pub fn commit<H: Digest>(&self) -> Result<(PedersenOpening, PedersenCommitment)> {
+        let mut hasher = H::new();
+        for solution in &self.solutions {
+            hasher.update(solution.as_bytes());
+        }
+        let s = reduce_to_scalar(&hasher.finalize());
+        let r = Scalar::random(&mut OsRng);
+
+        let c = generator_g() * s + generator_h() * r;
+
+        Ok((PedersenOpening { s, r }, PedersenCommitment { c }))
+    }
+
+    /// prove returns a proof that `opening` is a valid opening of `commitment`, bound to `m_s`.
+    pub fn prove(&self, commitment: &PedersenCommitment, opening: &PedersenOpening, m_s: Address) -> Result<PedersenProof> {
+        let k1 = Scalar::random(&mut OsRng);
+        let k2 = Scalar::random(&mut OsRng);
+        let t = generator_g() * k1 + generator_h() * k2;
+
+        let e = challenge(&commitment.c, &t, m_s);
+
+        let z1 = k1 + e * opening.s;
+        let z2 = k2 + e * opening.r;
+
+        Ok(PedersenProof { t, z1, z2 })
+    }
+}
+
+impl PedersenProof {
+    /// verify returns true if `self` proves knowledge of `commitment`'s opening bound to `m_s`.
+    pub fn verify(&self, commitment: &PedersenCommitment, m_s: Address) -> Result<bool> {
+        let e = challenge(&commitment.c, &self.t, m_s);
+        let lhs = generator_g() * self.z1 + generator_h() * self.z2;
+        let rhs = self.t + commitment.c * e;
+        Ok(lhs == rhs)
+    }
+
+    /// verify_and_export returns `commitment` and `self.t` SEC1-compressed (33 bytes each), and
+    /// `self.z1`/`self.z2` as 32-byte words, if the proof is valid and error otherwise. This is for
+    /// off-chain consumers: unlike `PuzzleSolutionProof::verify_and_export`, no on-chain verifier
+    /// decodes these fields, since a 33-byte compressed point doesn't fit a Solidity `bytes32`.
+    pub fn verify_and_export(
+        &self,
+        commitment: &PedersenCommitment,
+        m_s: Address,
+    ) -> Result<(Vec<u8>, Vec<u8>, Vec<u8>, Vec<u8>)> {
+        match self.verify(commitment, m_s)? {
+            true => Ok((
+                point_to_compressed(&commitment.c),
+                point_to_compressed(&self.t),
+                self.z1.to_bytes().to_vec(),
+                self.z2.to_bytes().to_vec(),
+            )),
+            false => Err(anyhow::Error::msg("Invalid proof")),
+        }
+    }
+}
+
+/// generator_g is the standard secp256k1 base point.
+fn generator_g() -> ProjectivePoint {
+    ProjectivePoint::GENERATOR
+}
+
+/// generator_h is a nothing-up-my-sleeve point with no known discrete log relative to `g`.
+fn generator_h() -> ProjectivePoint {
+    let tag = b"puzzle_solution_argument/PedersenPuzzleSolution/h";
+    let mut counter: u8 = 0;
+    loop {
+        let mut hasher = sha2::Sha256::new();
+        hasher.update(tag);
+        hasher.update([counter]);
+        let digest = hasher.finalize();
+
+        let mut compressed = [0u8; 33];
+        compressed[0] = 0x02;
+        compressed[1..].copy_from_slice(&digest);
+
+        if let Ok(encoded) = k256::EncodedPoint::from_bytes(&compressed[..]) {
+            let affine = k256::AffinePoint::from_encoded_point(&encoded);
+            if affine.is_some().into() {
+                return ProjectivePoint::from(affine.unwrap());
+            }
+        }
+        counter += 1;
+    }
+}
+
+/// reduce_to_scalar reduces a digest into a scalar mod the curve order. A digest shorter than 32
+/// bytes is left-padded with zeros; of a longer one, only the first 32 bytes are used.
+fn reduce_to_scalar(bytes: &[u8]) -> Scalar {
+    let mut padded = [0u8; 32];
+    let len = bytes.len().min(32);
+    padded[32 - len..].copy_from_slice(&bytes[..len]);
+    Scalar::reduce(U256::from_be_slice(&padded))
+}
+
+/// challenge computes `e = keccak256(C || T || m_s)` reduced mod the curve order.
+fn challenge(c: &ProjectivePoint, t: &ProjectivePoint, m_s: Address) -> Scalar {
+    let mut bytes = Vec::with_capacity(33 + 33 + 20);
+    bytes.extend_from_slice(c.to_affine().to_encoded_point(true).as_bytes());
+    bytes.extend_from_slice(t.to_affine().to_encoded_point(true).as_bytes());
+    bytes.extend_from_slice(&m_s.encode());
+
+    reduce_to_scalar(&keccak256(bytes))
+}
+
+/// point_to_compressed exports a curve point in SEC1-compressed form.
+fn point_to_compressed(p: &ProjectivePoint) -> Vec<u8> {
+    p.to_affine().to_encoded_point(true).as_bytes().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commit_and_prove_round_trip() {
+        let solutions = vec![
+            "solution1".to_string(),
+            "solution2".to_string(),
+            "solution3".to_string(),
+        ];
+        let puzzle_solution = PedersenPuzzleSolution::new(solutions);
+        let (opening, commitment) = puzzle_solution.commit::<sha2::Sha256>().unwrap();
+
+        let m_s = Address::random();
+        let proof = puzzle_solution.prove(&commitment, &opening, m_s).unwrap();
+
+        assert!(proof.verify(&commitment, m_s).unwrap());
+    }
+
+    #[test]
+    fn test_proof_rejects_wrong_m_s() {
+        let solutions = vec![
+            "solution1".to_string(),
+            "solution2".to_string(),
+            "solution3".to_string(),
+        ];
+        let puzzle_solution = PedersenPuzzleSolution::new(solutions);
+        let (opening, commitment) = puzzle_solution.commit::<sha2::Sha256>().unwrap();
+
+        let m_s = Address::random();
+        let other_m_s = Address::random();
+        let proof = puzzle_solution.prove(&commitment, &opening, m_s).unwrap();
+
+        assert!(!proof.verify(&commitment, other_m_s).unwrap());
+    }
+
+    #[test]
+    fn test_different_blinding_hides_same_solution() {
+        let solutions = vec![
+            "solution1".to_string(),
+            "solution2".to_string(),
+            "solution3".to_string(),
+        ];
+        let puzzle_solution = PedersenPuzzleSolution::new(solutions);
+        let (_, commitment_a) = puzzle_solution.commit::<sha2::Sha256>().unwrap();
+        let (_, commitment_b) = puzzle_solution.commit::<sha2::Sha256>().unwrap();
+
+        assert!(commitment_a.c != commitment_b.c);
+    }
+}
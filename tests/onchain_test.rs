@@ -0,0 +1,69 @@
+//! End-to-end test of proof submission against a local Anvil node: derive a commitment, produce a
+//! proof, submit it through `onchain::submit_proof`, and confirm the contract records it as solved.
+
+use std::sync::Arc;
+
+use ethers_contract::ContractFactory;
+use ethers_core::abi::Abi;
+use ethers_core::utils::Anvil;
+use ethers_providers::{Http, Middleware, Provider};
+use ethers_signers::Signer;
+
+use puzzle_solution_argument::onchain::submit_proof;
+use puzzle_solution_argument::PuzzleSolution;
+
+const PUZZLE_VERIFIER_ABI: &str = include_str!("../contracts/PuzzleVerifier.abi.json");
+const PUZZLE_VERIFIER_BYTECODE: &str = include_str!("../contracts/PuzzleVerifier.bin");
+
+#[tokio::test]
+async fn test_submit_proof_against_local_node() {
+    let anvil = Anvil::new().spawn();
+    let wallet = anvil.keys()[0].clone();
+    let provider = Provider::<Http>::try_from(anvil.endpoint())
+        .unwrap()
+        .with_sender(wallet.address());
+    let client = Arc::new(provider);
+
+    let abi: Abi = serde_json::from_str(PUZZLE_VERIFIER_ABI).unwrap();
+    let bytecode = ethers_core::types::Bytes::from(
+        hex::decode(PUZZLE_VERIFIER_BYTECODE.trim()).unwrap(),
+    );
+    let factory = ContractFactory::new(abi, bytecode, client.clone());
+    let contract = factory.deploy(()).unwrap().send().await.unwrap();
+
+    let solutions = vec![
+        "solution1".to_string(),
+        "solution2".to_string(),
+        "solution3".to_string(),
+    ];
+    let puzzle_solution = PuzzleSolution::new(solutions);
+    let (w, puzzle_commitment) = puzzle_solution
+        .get_solution_commitment::<sha2::Sha256>()
+        .unwrap();
+    let m_s = wallet.address();
+    let proof = puzzle_solution
+        .get_solution_proof(w, puzzle_commitment, m_s)
+        .await
+        .unwrap();
+
+    let (_, r_abi, s_abi, v_abi, m_s_bytes) = proof.verify_and_export(puzzle_commitment).unwrap();
+
+    let receipt = submit_proof(
+        client.clone(),
+        contract.address(),
+        (r_abi, s_abi, v_abi, m_s_bytes),
+        puzzle_commitment,
+    )
+    .await
+    .unwrap();
+
+    assert!(receipt.status.unwrap().as_u64() == 1);
+
+    let solved: bool = contract
+        .method::<_, bool>("isSolved", puzzle_commitment)
+        .unwrap()
+        .call()
+        .await
+        .unwrap();
+    assert!(solved);
+}